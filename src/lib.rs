@@ -110,6 +110,16 @@
 //!   upholding a major security requirement).
 
 #![no_std]
+// `generic_const_exprs` is required by the `arithmetic` feature (see `numeric::arithmetic`) to
+// compute the bounds of a widened ranged integer from the bounds of its operands, and by the
+// `compact` feature (see `numeric::compact`) to select a storage-optimised backing type from a
+// range's span -- it is incomplete and nightly-only, so it is only enabled when one of those
+// features is.
+#![cfg_attr(
+    any(feature = "arithmetic", feature = "compact"),
+    feature(generic_const_exprs)
+)]
+#![cfg_attr(any(feature = "arithmetic", feature = "compact"), allow(incomplete_features))]
 
 /// Contains ranged numeric types.
 #[cfg(feature = "numeric")]
@@ -64,8 +64,32 @@ impl<IntType: Display> Display for RangedError<IntType> {
 #[cfg(feature = "std")]
 impl<IntType: Display> std::error::Error for RangedError<IntType> {}
 
+/// An error parsing a ranged integer from a string, via [`FromStr`](::core::str::FromStr).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RangedParseError<IntType> {
+    /// The string could not be parsed as the base integer type.
+    ParseInt(::core::num::ParseIntError),
+
+    /// The string was parsed as the base integer type, but the value fell outside the ranged
+    /// type's `MIN..=MAX`.
+    OutOfRange(RangedError<IntType>),
+}
+
+impl<IntType: Display> Display for RangedParseError<IntType> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            RangedParseError::ParseInt(error) => Display::fmt(error, f),
+            RangedParseError::OutOfRange(error) => Display::fmt(error, f),
+        }
+    }
+}
+
+// See the remark on `RangedError`'s `Error` impl above -- the same applies here.
+#[cfg(feature = "std")]
+impl<IntType: Display> std::error::Error for RangedParseError<IntType> {}
+
 macro_rules! _int_define {
-    ($name:tt($int_ty:ty)) => {
+    ($name:tt($int_ty:ty as $uint_ty:ty), $iter_name:tt) => {
         #[doc = concat!("A ranged [`", stringify!($int_ty), "`] type with a value between `MIN` \
         and `MAX`")]
         #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -115,6 +139,79 @@ macro_rules! _int_define {
                 Self(value)
             }
 
+            /// Create a new instance of the type, clamping `value` into `MIN..=MAX` if it falls
+            /// outside it.
+            #[inline]
+            pub const fn new_saturating(value: $int_ty) -> Self {
+                debug_assert!(MIN <= MAX, "invalid range: MIN must not exceed MAX");
+
+                if value < MIN {
+                    Self(MIN)
+                } else if value > MAX {
+                    Self(MAX)
+                } else {
+                    Self(value)
+                }
+            }
+
+            /// Create a new instance of the type, mapping `value` into `MIN..=MAX` by modular
+            /// reduction if it falls outside it (e.g., one above `MAX` wraps to `MIN`).
+            ///
+            /// # Remarks
+            ///
+            /// Unlike [`new_saturating`](Self::new_saturating), which clamps, this preserves the
+            /// "distance" `value` overshoots the range by, wrapping it back around -- useful for
+            /// cyclic quantities (e.g., angles, days of the week).
+            ///
+            /// The reduction is performed in the same-width unsigned representation (`
+            #[doc = stringify!($uint_ty)]
+            /// ` for this type), which can represent the distance between any two values of the
+            /// base type without overflowing -- this sidesteps needing a genuinely wider type,
+            /// which does not exist for `RangedU128`/`RangedI128`.
+            #[inline]
+            pub const fn new_wrapping(value: $int_ty) -> Self {
+                debug_assert!(MIN <= MAX, "invalid range: MIN must not exceed MAX");
+
+                if value >= MIN && value <= MAX {
+                    return Self(value);
+                }
+
+                // Flipping the sign bit re-bases a signed value onto the unsigned number line
+                // whilst preserving order (for unsigned types this is a no-op, as `IntType::MIN`
+                // is `0`) -- this lets the arithmetic below stay entirely within the unsigned
+                // type without ever needing to represent a value outside `IntType::MIN..=MAX`.
+                const SIGN_BIT: $uint_ty = <$int_ty>::MIN as $uint_ty;
+
+                let biased_value = (value as $uint_ty) ^ SIGN_BIT;
+                let biased_min = (MIN as $uint_ty) ^ SIGN_BIT;
+                let biased_max = (MAX as $uint_ty) ^ SIGN_BIT;
+                let span = biased_max - biased_min + 1;
+
+                let biased_result = if biased_value > biased_max {
+                    let excess = biased_value - biased_max;
+                    biased_min + (excess - 1) % span
+                } else {
+                    let deficit = biased_min - biased_value;
+                    biased_max - (deficit - 1) % span
+                };
+
+                Self((biased_result ^ SIGN_BIT) as $int_ty)
+            }
+
+            /// Clamp `self` into `min..=max`.
+            #[inline]
+            pub const fn clamp(self, min: Self, max: Self) -> Self {
+                debug_assert!(min.0 <= max.0, "invalid range: `min` must not exceed `max`");
+
+                if self.0 < min.0 {
+                    min
+                } else if self.0 > max.0 {
+                    max
+                } else {
+                    self
+                }
+            }
+
             /// Retrieve the inner value of the type.
             ///
             /// # Remarks
@@ -124,6 +221,43 @@ macro_rules! _int_define {
             pub const fn inner(self) -> $int_ty {
                 self.0
             }
+
+            /// Widen this value's range to `NMIN..=NMAX`.
+            ///
+            /// This fails to compile unless `NMIN..=NMAX` contains `MIN..=MAX` -- in that case,
+            /// `self` is already a valid `NMIN..=NMAX` value, so the conversion cannot fail (unlike
+            /// [`new`](Self::new), this never needs to return a `Result`).
+            #[inline]
+            pub const fn expand<const NMIN: $int_ty, const NMAX: $int_ty>(self) -> $name<NMIN, NMAX> {
+                trait AssertWiden<
+                    const MIN: $int_ty,
+                    const MAX: $int_ty,
+                    const NMIN: $int_ty,
+                    const NMAX: $int_ty,
+                > {
+                    const OK: () = assert!(
+                        NMIN <= MIN && MAX <= NMAX,
+                        "expand: the target range must contain the source range"
+                    );
+                }
+                impl<const MIN: $int_ty, const MAX: $int_ty, const NMIN: $int_ty, const NMAX: $int_ty>
+                    AssertWiden<MIN, MAX, NMIN, NMAX> for ()
+                {
+                }
+                let _: () = <() as AssertWiden<MIN, MAX, NMIN, NMAX>>::OK;
+
+                // SAFETY: the const assertion above guarantees `NMIN <= MIN` and `MAX <= NMAX`,
+                // and `self.0` is in `MIN..=MAX` by construction, so it is always in
+                // `NMIN..=NMAX`.
+                unsafe { $name::new_unchecked(self.0) }
+            }
+
+            /// Equivalent to [`expand`](Self::expand), provided under this name for readers
+            /// coming from the `ranged_integers` crate's `AsRanged` trait.
+            #[inline]
+            pub const fn as_ranged<const NMIN: $int_ty, const NMAX: $int_ty>(self) -> $name<NMIN, NMAX> {
+                self.expand()
+            }
         }
 
         impl<const MIN: $int_ty, const MAX: $int_ty> ::core::fmt::Display for $name<MIN, MAX> {
@@ -172,6 +306,19 @@ macro_rules! _int_define {
             }
         }
 
+        impl<const MIN: $int_ty, const MAX: $int_ty> ::core::str::FromStr for $name<MIN, MAX> {
+            type Err = $crate::numeric::RangedParseError<$int_ty>;
+
+            #[inline]
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let value = s
+                    .parse::<$int_ty>()
+                    .map_err($crate::numeric::RangedParseError::ParseInt)?;
+
+                Self::new(value).map_err($crate::numeric::RangedParseError::OutOfRange)
+            }
+        }
+
         /* Considered trait impls
          *
          * I did consider implementing traits like ::core::ops::Add, ::core::ops::Multiply, etc,
@@ -190,29 +337,259 @@ macro_rules! _int_define {
          *     behaviour could be unexpected (i.e., the consumer may think that adding two ranged
          *     integers should produce a ranged integer as well).
          *
-         * As a result, I have decided to put more thought into it before making the decision (the
-         * crate is still in the development phase so backwards compatibility isn't a major issue
-         * at the moment, it is just a lot of wasted effort if I choose to go the other way).
+         * This has now been resolved in two parts:
+         *
+         * - On stable, `checked_add`/`checked_sub`/`checked_mul` are provided below -- these stay
+         *   within `Self` (re-validating the result against `MIN..=MAX`) and so sidestep the
+         *   "what range does the output have" question entirely, at the cost of a `Result`.
+         *
+         * - Behind the nightly-only `arithmetic` feature (see `arithmetic.rs`), `Add`/`Sub`/`Mul`
+         *   are implemented properly: the polymorphic-constant restriction mentioned above is
+         *   lifted by `generic_const_exprs`, which lets the output bounds be computed from the
+         *   operands' bounds and the result widened to a type guaranteed not to overflow.
          */
+
+        impl<const MIN: $int_ty, const MAX: $int_ty> $name<MIN, MAX> {
+            /// Add `rhs` to this value, re-validating the result against `MIN..=MAX`.
+            ///
+            /// # Remarks
+            ///
+            /// This stays within `Self` rather than widening the result -- if a non-overflowing
+            /// widened result is required instead, see the nightly-only `arithmetic` feature,
+            /// which implements [`Add`](::core::ops::Add) with bound propagation for the 8- to
+            /// 64-bit fixed-width types.
+            #[inline]
+            pub const fn checked_add(
+                self,
+                rhs: Self,
+            ) -> Result<Self, $crate::numeric::RangedError<$int_ty>> {
+                match self.0.checked_add(rhs.0) {
+                    Some(value) => Self::new(value),
+                    // The sum overflows upwards only when `rhs` is positive.
+                    None if rhs.0 > 0 => Err($crate::numeric::RangedError::TooLarge {
+                        value: <$int_ty>::MAX,
+                        maximum: MAX,
+                    }),
+                    None => Err($crate::numeric::RangedError::TooSmall {
+                        value: <$int_ty>::MIN,
+                        minimum: MIN,
+                    }),
+                }
+            }
+
+            /// Subtract `rhs` from this value, re-validating the result against `MIN..=MAX`.
+            ///
+            /// # Remarks
+            ///
+            /// This stays within `Self` rather than widening the result -- if a non-overflowing
+            /// widened result is required instead, see the nightly-only `arithmetic` feature,
+            /// which implements [`Sub`](::core::ops::Sub) with bound propagation for the 8- to
+            /// 64-bit fixed-width types.
+            #[inline]
+            pub const fn checked_sub(
+                self,
+                rhs: Self,
+            ) -> Result<Self, $crate::numeric::RangedError<$int_ty>> {
+                match self.0.checked_sub(rhs.0) {
+                    Some(value) => Self::new(value),
+                    // The difference overflows downwards only when `rhs` is positive.
+                    None if rhs.0 > 0 => Err($crate::numeric::RangedError::TooSmall {
+                        value: <$int_ty>::MIN,
+                        minimum: MIN,
+                    }),
+                    None => Err($crate::numeric::RangedError::TooLarge {
+                        value: <$int_ty>::MAX,
+                        maximum: MAX,
+                    }),
+                }
+            }
+
+            /// Multiply this value by `rhs`, re-validating the result against `MIN..=MAX`.
+            ///
+            /// # Remarks
+            ///
+            /// This stays within `Self` rather than widening the result -- if a non-overflowing
+            /// widened result is required instead, see the nightly-only `arithmetic` feature,
+            /// which implements [`Mul`](::core::ops::Mul) with bound propagation for the 8- to
+            /// 64-bit fixed-width types.
+            #[inline]
+            pub const fn checked_mul(
+                self,
+                rhs: Self,
+            ) -> Result<Self, $crate::numeric::RangedError<$int_ty>> {
+                match self.0.checked_mul(rhs.0) {
+                    Some(value) => Self::new(value),
+                    // The product overflows upwards only when both operands share a sign.
+                    None if (self.0 > 0) == (rhs.0 > 0) => {
+                        Err($crate::numeric::RangedError::TooLarge {
+                            value: <$int_ty>::MAX,
+                            maximum: MAX,
+                        })
+                    }
+                    None => Err($crate::numeric::RangedError::TooSmall {
+                        value: <$int_ty>::MIN,
+                        minimum: MIN,
+                    }),
+                }
+            }
+
+            /// Return an iterator over every value of the type, from `MIN` to `MAX` inclusive.
+            #[inline]
+            pub const fn iter() -> $iter_name<MIN, MAX> {
+                $iter_name {
+                    next: if MIN <= MAX { Some(MIN) } else { None },
+                }
+            }
+
+            /// Index `slice` using this value, returning `None` if it falls outside the
+            /// slice's bounds.
+            ///
+            /// # Remarks
+            ///
+            /// A value in range does not guarantee a valid index into an arbitrary slice --
+            /// `slice` may simply be shorter than `MAX + 1` -- hence the `Option`. For indexing a
+            /// `[T; N]` where `Self` is known to cover exactly `0..=N-1`, see
+            /// [`RangedUsize::index_array`].
+            #[inline]
+            pub fn index<T>(self, slice: &[T]) -> Option<&T> {
+                // A value that does not fit in a `usize` (negative, or too wide) is never a valid
+                // index, rather than being truncated onto one.
+                <usize as ::core::convert::TryFrom<$int_ty>>::try_from(self.inner())
+                    .ok()
+                    .and_then(|index| slice.get(index))
+            }
+
+            /// Index `slice` using this value, without bounds checking.
+            ///
+            /// # Safety
+            ///
+            /// `self.inner() as usize` must be a valid index into `slice` (i.e., less than
+            /// `slice.len()`).
+            #[inline]
+            pub unsafe fn index_unchecked<T>(self, slice: &[T]) -> &T {
+                // SAFETY: guaranteed by the caller.
+                unsafe { slice.get_unchecked(self.inner() as usize) }
+            }
+        }
+
+        #[doc = concat!(
+            "An iterator over every value of [`", stringify!($name), "`], from `MIN` to `MAX` \
+            inclusive."
+        )]
+        #[derive(Debug, Clone)]
+        pub struct $iter_name<const MIN: $int_ty, const MAX: $int_ty> {
+            // `None` once the final value has been yielded -- this also correctly handles the
+            // `MAX == $int_ty::MAX` edge case, where `next + 1` would otherwise overflow the
+            // base type.
+            next: Option<$int_ty>,
+        }
+
+        impl<const MIN: $int_ty, const MAX: $int_ty> ::core::iter::Iterator for $iter_name<MIN, MAX> {
+            type Item = $name<MIN, MAX>;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                let value = self.next?;
+                self.next = if value == MAX { None } else { Some(value + 1) };
+
+                // SAFETY: `value` starts at `MIN` and this is only reached while `value <= MAX`.
+                Some(unsafe { $name::new_unchecked(value) })
+            }
+        }
     };
 }
 
+/// Bound-propagating arithmetic operators (`Add`, `Sub`, `Mul`), gated behind the nightly-only
+/// `arithmetic` feature.
+#[cfg(feature = "arithmetic")]
+mod arithmetic;
+
+/// `Serialize`/`Deserialize` implementations, gated behind the `serde` feature.
+#[cfg(feature = "serde")]
+mod serde;
+
+/// A storage-optimised ranged integer, gated behind the nightly-only `compact` feature.
+#[cfg(feature = "compact")]
+mod compact;
+
+#[cfg(feature = "compact")]
+pub use compact::RangedCompact;
+
+/* Considered: a `niche` feature for scalar-valid-range-based layout optimisation
+ *
+ * It was suggested that a `Ranged*<MIN, MAX>` could be given `#[rustc_layout_scalar_valid_range_start]`/
+ * `#[rustc_layout_scalar_valid_range_end]` attributes (the same mechanism `core` uses for e.g.
+ * `NonZeroU8`) so that `Option<Ranged*<MIN, MAX>>` is niche-optimised down to the size of the base
+ * type. I looked into this and do not believe it is implementable, for two independent reasons:
+ *
+ * - Those attributes are compiler-internal (`rustc_attrs`) and rejected outright for any crate
+ *   other than `core`/`std` themselves -- they are allow-listed to the standard library's own
+ *   crates at the compiler level, not merely feature-gated behind `#![feature(rustc_attrs)]` as
+ *   the niches RFC discussion might suggest; I confirmed this produces a hard compile error
+ *   ("attributes starting with `rustc` are reserved for use by the `rustc` compiler") even with
+ *   that feature enabled on nightly.
+ *
+ * - Even if they were available to third-party crates, the attributes take a single literal
+ *   integer, fixed for the struct definition as a whole -- they cannot reference `MIN`/`MAX`
+ *   const generic parameters, which differ per monomorphisation of `Ranged*<MIN, MAX>`. The
+ *   struct would need one concrete, unparameterised valid range baked in at definition time,
+ *   which is fundamentally incompatible with how these types are generic over their bounds.
+ *
+ * A hand-written niche (e.g. via a private non-exhaustive discriminant) would mean abandoning
+ * `#[repr(transparent)]` and the direct `$int_ty` bit-for-bit representation the rest of this
+ * module relies on (`as_ref`, `From<$name> for $int_ty`, etc. all assume the layouts match), so
+ * I have not pursued it. If `rustc_layout_scalar_valid_range_*` is ever stabilised for generic use,
+ * this would be worth revisiting.
+ */
+
 // Unsigned ranged types
-_int_define!(RangedU8(u8));
-_int_define!(RangedU16(u16));
-_int_define!(RangedU32(u32));
-_int_define!(RangedU64(u64));
-_int_define!(RangedU128(u128));
+_int_define!(RangedU8(u8 as u8), RangedU8Iter);
+_int_define!(RangedU16(u16 as u16), RangedU16Iter);
+_int_define!(RangedU32(u32 as u32), RangedU32Iter);
+_int_define!(RangedU64(u64 as u64), RangedU64Iter);
+_int_define!(RangedU128(u128 as u128), RangedU128Iter);
+_int_define!(RangedUsize(usize as usize), RangedUsizeIter);
 
 // Signed ranged types
-_int_define!(RangedI8(i8));
-_int_define!(RangedI16(i16));
-_int_define!(RangedI32(i32));
-_int_define!(RangedI64(i64));
-_int_define!(RangedI128(i128));
+_int_define!(RangedI8(i8 as u8), RangedI8Iter);
+_int_define!(RangedI16(i16 as u16), RangedI16Iter);
+_int_define!(RangedI32(i32 as u32), RangedI32Iter);
+_int_define!(RangedI64(i64 as u64), RangedI64Iter);
+_int_define!(RangedI128(i128 as u128), RangedI128Iter);
+_int_define!(RangedIsize(isize as usize), RangedIsizeIter);
+
+impl<const MAX: usize> RangedUsize<0, MAX> {
+    /// Index a `[T; N]` using this value, where `N == MAX + 1`, eliding the bounds check
+    /// entirely.
+    ///
+    /// # Remarks
+    ///
+    /// This only compiles when `N == MAX + 1` (i.e., when `Self` is `RangedUsize<0, N - 1>`
+    /// and therefore covers every index of `array`) -- mismatched lengths are rejected at
+    /// compile time by the const assertion below, sidestepping the usual inability to bound
+    /// constant generics against one another (see [`RangedError::InvalidRange`]).
+    #[inline]
+    pub fn index_array<T, const N: usize>(self, array: &[T; N]) -> &T {
+        trait AssertArrayLen<const N: usize, const MAX: usize> {
+            const OK: () = assert!(N == MAX + 1, "array length must equal MAX + 1");
+        }
+        impl<const N: usize, const MAX: usize> AssertArrayLen<N, MAX> for () {}
+        let _: () = <() as AssertArrayLen<N, MAX>>::OK;
+
+        // SAFETY: the const assertion above guarantees `Self` covers exactly `0..=N-1`, and
+        // `self.inner()` is in `0..=MAX` by construction, so it is always a valid index.
+        unsafe { array.get_unchecked(self.inner()) }
+    }
+}
 
 #[cfg(test)]
 mod tests {
+    // `#![no_std]` means `std` isn't in the prelude -- but `to_string()`/`parse()` round-tripping
+    // in the `from_str_*` tests below is far more convenient with it than with `alloc` alone.
+    extern crate std;
+
+    use super::RangedUsize;
+
     // This test is here for convenience (i.e., my IDE doesn't recognise generated tests and
     // doesn't offer test running -- I am sure there's a way around it but I do not care)
     #[test]
@@ -222,6 +599,9 @@ mod tests {
         ($module:tt, $name:tt($int_ty:ty)) => {
             mod $module {
                 use $crate::numeric::*;
+                // `extern crate std;` lives on `tests` (see above) rather than the crate root (the
+                // crate itself is `#![no_std]`), so it must be reached via `super` here.
+                use super::std::string::ToString;
 
                 // Convenience constants
                 const MIN: $int_ty = <$int_ty>::MIN;
@@ -280,6 +660,142 @@ mod tests {
                     let ranged = $name::<MIN, MAX>::new(number).unwrap();
                     assert_eq!(number, *ranged);
                 }
+
+                #[test]
+                fn iter_covers_range() {
+                    const LOW: $int_ty = 3 as $int_ty;
+                    const HIGH: $int_ty = 7 as $int_ty;
+
+                    let mut iter = $name::<LOW, HIGH>::iter();
+                    let mut expected = LOW;
+                    let mut count: u32 = 0;
+
+                    for ranged in &mut iter {
+                        assert_eq!(ranged.inner(), expected);
+                        expected += 1;
+                        count += 1;
+                    }
+
+                    assert_eq!(count, (HIGH - LOW + 1) as u32);
+                }
+
+                #[test]
+                fn index_in_bounds() {
+                    let slice = [10, 20, 30, 40, 50];
+                    let ranged =
+                        $name::<{ 0 as $int_ty }, { 4 as $int_ty }>::new(2 as $int_ty).unwrap();
+                    assert_eq!(ranged.index(&slice), Some(&30));
+                }
+
+                #[test]
+                fn new_saturating_clamps_low() {
+                    const LOW: $int_ty = 3 as $int_ty;
+                    const HIGH: $int_ty = 7 as $int_ty;
+
+                    let ranged = $name::<LOW, HIGH>::new_saturating(LOW - 1);
+                    assert_eq!(ranged.inner(), LOW);
+                }
+
+                #[test]
+                fn new_saturating_clamps_high() {
+                    const LOW: $int_ty = 3 as $int_ty;
+                    const HIGH: $int_ty = 7 as $int_ty;
+
+                    let ranged = $name::<LOW, HIGH>::new_saturating(HIGH + 1);
+                    assert_eq!(ranged.inner(), HIGH);
+                }
+
+                #[test]
+                fn new_saturating_in_range() {
+                    const LOW: $int_ty = 3 as $int_ty;
+                    const HIGH: $int_ty = 7 as $int_ty;
+
+                    let ranged = $name::<LOW, HIGH>::new_saturating(LOW + 1);
+                    assert_eq!(ranged.inner(), LOW + 1);
+                }
+
+                #[test]
+                fn new_wrapping_one_above_wraps_to_min() {
+                    const LOW: $int_ty = 3 as $int_ty;
+                    const HIGH: $int_ty = 7 as $int_ty;
+
+                    let ranged = $name::<LOW, HIGH>::new_wrapping(HIGH + 1);
+                    assert_eq!(ranged.inner(), LOW);
+                }
+
+                #[test]
+                fn new_wrapping_one_below_wraps_to_max() {
+                    const LOW: $int_ty = 3 as $int_ty;
+                    const HIGH: $int_ty = 7 as $int_ty;
+
+                    let ranged = $name::<LOW, HIGH>::new_wrapping(LOW - 1);
+                    assert_eq!(ranged.inner(), HIGH);
+                }
+
+                #[test]
+                fn new_wrapping_in_range() {
+                    const LOW: $int_ty = 3 as $int_ty;
+                    const HIGH: $int_ty = 7 as $int_ty;
+
+                    let ranged = $name::<LOW, HIGH>::new_wrapping(LOW + 1);
+                    assert_eq!(ranged.inner(), LOW + 1);
+                }
+
+                #[test]
+                fn clamp_bounds_value() {
+                    let low = $name::<MIN, MAX>::new(MIN).unwrap();
+                    let high = $name::<MIN, MAX>::new(MAX).unwrap();
+                    let value = $name::<MIN, MAX>::new(MIN).unwrap();
+
+                    assert_eq!(value.clamp(low, high).inner(), MIN);
+                }
+
+                #[test]
+                fn expand_widens_bounds() {
+                    const NARROW_LOW: $int_ty = MIN;
+                    const NARROW_HIGH: $int_ty = MIN + 1 as $int_ty;
+
+                    let narrow = $name::<NARROW_LOW, NARROW_HIGH>::new(MIN).unwrap();
+                    let wide: $name<MIN, MAX> = narrow.expand();
+                    assert_eq!(wide.inner(), MIN);
+                }
+
+                #[test]
+                fn as_ranged_widens_bounds() {
+                    const NARROW_LOW: $int_ty = MIN;
+                    const NARROW_HIGH: $int_ty = MIN + 1 as $int_ty;
+
+                    let narrow = $name::<NARROW_LOW, NARROW_HIGH>::new(MIN).unwrap();
+                    let wide: $name<MIN, MAX> = narrow.as_ranged();
+                    assert_eq!(wide.inner(), MIN);
+                }
+
+                #[test]
+                fn index_out_of_bounds() {
+                    let slice = [10, 20];
+                    let ranged =
+                        $name::<{ 0 as $int_ty }, { 4 as $int_ty }>::new(4 as $int_ty).unwrap();
+                    assert_eq!(ranged.index(&slice), None);
+                }
+
+                #[test]
+                fn from_str_valid() {
+                    let number = MIN;
+                    let ranged: $name<MIN, MAX> = number.to_string().parse().unwrap();
+                    assert_eq!(ranged.inner(), number);
+                }
+
+                #[test]
+                fn from_str_out_of_range() {
+                    let err = MIN.to_string().parse::<$name<MAX, MAX>>().unwrap_err();
+                    assert!(matches!(err, RangedParseError::OutOfRange(_)));
+                }
+
+                #[test]
+                fn from_str_invalid() {
+                    let err = "not a number".parse::<$name<MIN, MAX>>().unwrap_err();
+                    assert!(matches!(err, RangedParseError::ParseInt(_)));
+                }
             }
         };
     }
@@ -290,6 +806,7 @@ mod tests {
     _test_ranged!(ranged_u32, RangedU32(u32));
     _test_ranged!(ranged_u64, RangedU64(u64));
     _test_ranged!(ranged_u128, RangedU128(u128));
+    _test_ranged!(ranged_usize, RangedUsize(usize));
 
     // Signed tests
     _test_ranged!(ranged_i8, RangedI8(i8));
@@ -297,4 +814,14 @@ mod tests {
     _test_ranged!(ranged_i32, RangedI32(i32));
     _test_ranged!(ranged_i64, RangedI64(i64));
     _test_ranged!(ranged_i128, RangedI128(i128));
+    _test_ranged!(ranged_isize, RangedIsize(isize));
+
+    #[test]
+    fn index_array_elides_bounds_check() {
+        let array = [1, 2, 3, 4, 5];
+
+        for ranged in RangedUsize::<0, 4>::iter() {
+            assert_eq!(ranged.index_array(&array), &array[ranged.inner()]);
+        }
+    }
 }
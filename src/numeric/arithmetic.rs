@@ -0,0 +1,289 @@
+//! Bound-propagating arithmetic for ranged integers.
+//!
+//! This module is only compiled when the `arithmetic` feature is enabled, and requires
+//! `generic_const_exprs` -- an incomplete, nightly-only language feature which allows the
+//! bounds of a generated type to be computed from the const generics of its operands (e.g.
+//! `RangedU8<A, B> + RangedU8<C, D>` can produce a `RangedU16<{A+C}, {B+D}>`).
+//!
+//! Each operator widens to the next integer type up so that the result is guaranteed to be
+//! representable without overflowing the base type -- `Add`/`Mul` widen to the next type of the
+//! same signedness, whilst `Sub` widens to the signed type of the next width (since the
+//! difference of two unsigned ranged integers may be negative).
+//!
+//! # Remarks
+//!
+//! There is no further widening beyond `i128`/`u128`, so the top of each signedness chain has no
+//! `Add`/`Sub`/`Mul` implementation here -- callers working with `RangedU128`/`RangedI128` should
+//! use the stable `checked_add`/`checked_sub`/`checked_mul` methods instead.
+//!
+//! The `where [(); { .. } as usize]:` bounds scattered throughout are required by
+//! `generic_const_exprs` itself -- it cannot (yet) prove a computed constant is well-formed
+//! without being told to evaluate it first.
+
+use super::*;
+
+/// The smallest of four values, used to compute the lower bound of a widened signed
+/// multiplication (the product of two ranged integers is monotonic in each operand, so the
+/// extrema always occur at one of the four corners of the bounding box).
+pub const fn _min4(a: i128, b: i128, c: i128, d: i128) -> i128 {
+    let mut min = a;
+    if b < min {
+        min = b;
+    }
+    if c < min {
+        min = c;
+    }
+    if d < min {
+        min = d;
+    }
+    min
+}
+
+/// The largest of four values -- see [`_min4`].
+pub const fn _max4(a: i128, b: i128, c: i128, d: i128) -> i128 {
+    let mut max = a;
+    if b > max {
+        max = b;
+    }
+    if c > max {
+        max = c;
+    }
+    if d > max {
+        max = d;
+    }
+    max
+}
+
+/// Implements widening `Add`/`Mul` from an unsigned ranged type to the next unsigned ranged type
+/// up -- both operators stay monotonic across non-negative ranges, so the output bounds are
+/// simply the pairwise sums/products of the input bounds.
+macro_rules! _widen_unsigned_add_mul {
+    ($narrow:tt($narrow_ty:ty), $wide:tt($wide_ty:ty)) => {
+        impl<const A: $narrow_ty, const B: $narrow_ty, const C: $narrow_ty, const D: $narrow_ty>
+            ::core::ops::Add<$narrow<C, D>> for $narrow<A, B>
+        where
+            [(); { A as $wide_ty + C as $wide_ty } as usize]:,
+            [(); { B as $wide_ty + D as $wide_ty } as usize]:,
+        {
+            type Output =
+                $wide<{ A as $wide_ty + C as $wide_ty }, { B as $wide_ty + D as $wide_ty }>;
+
+            #[inline]
+            fn add(self, rhs: $narrow<C, D>) -> Self::Output {
+                // SAFETY: the output bounds are `MIN = A + C` and `MAX = B + D`, and
+                // `self.inner()` is in `A..=B` whilst `rhs.inner()` is in `C..=D`, so the sum is
+                // always in `MIN..=MAX`.
+                unsafe {
+                    $wide::new_unchecked(self.inner() as $wide_ty + rhs.inner() as $wide_ty)
+                }
+            }
+        }
+
+        impl<const A: $narrow_ty, const B: $narrow_ty, const C: $narrow_ty, const D: $narrow_ty>
+            ::core::ops::Mul<$narrow<C, D>> for $narrow<A, B>
+        where
+            [(); { A as $wide_ty * C as $wide_ty } as usize]:,
+            [(); { B as $wide_ty * D as $wide_ty } as usize]:,
+        {
+            type Output =
+                $wide<{ A as $wide_ty * C as $wide_ty }, { B as $wide_ty * D as $wide_ty }>;
+
+            #[inline]
+            fn mul(self, rhs: $narrow<C, D>) -> Self::Output {
+                // SAFETY: as both ranges are non-negative, the product is monotonic in each
+                // operand, so the extrema are `MIN * MIN` and `MAX * MAX`.
+                unsafe {
+                    $wide::new_unchecked(self.inner() as $wide_ty * rhs.inner() as $wide_ty)
+                }
+            }
+        }
+    };
+}
+
+/// Implements widening `Sub` from an unsigned ranged type to the signed ranged type of the next
+/// width up (the difference of two unsigned values may be negative).
+macro_rules! _widen_unsigned_sub {
+    ($narrow:tt($narrow_ty:ty), $wide_signed:tt($wide_ty:ty)) => {
+        impl<const A: $narrow_ty, const B: $narrow_ty, const C: $narrow_ty, const D: $narrow_ty>
+            ::core::ops::Sub<$narrow<C, D>> for $narrow<A, B>
+        where
+            [(); { A as $wide_ty - D as $wide_ty } as usize]:,
+            [(); { B as $wide_ty - C as $wide_ty } as usize]:,
+        {
+            type Output =
+                $wide_signed<{ A as $wide_ty - D as $wide_ty }, { B as $wide_ty - C as $wide_ty }>;
+
+            #[inline]
+            fn sub(self, rhs: $narrow<C, D>) -> Self::Output {
+                // SAFETY: the smallest possible difference is `MIN - MAX_rhs` and the largest is
+                // `MAX - MIN_rhs`, both of which are the declared bounds of the output type.
+                unsafe {
+                    $wide_signed::new_unchecked(self.inner() as $wide_ty - rhs.inner() as $wide_ty)
+                }
+            }
+        }
+    };
+}
+
+/// Implements widening `Add`/`Sub` between two signed ranged types of the same width (the
+/// formulae are identical to the unsigned case as they do not rely on non-negativity).
+macro_rules! _widen_signed_add_sub {
+    ($narrow:tt($narrow_ty:ty), $wide:tt($wide_ty:ty)) => {
+        impl<const A: $narrow_ty, const B: $narrow_ty, const C: $narrow_ty, const D: $narrow_ty>
+            ::core::ops::Add<$narrow<C, D>> for $narrow<A, B>
+        where
+            [(); { A as $wide_ty + C as $wide_ty } as usize]:,
+            [(); { B as $wide_ty + D as $wide_ty } as usize]:,
+        {
+            type Output =
+                $wide<{ A as $wide_ty + C as $wide_ty }, { B as $wide_ty + D as $wide_ty }>;
+
+            #[inline]
+            fn add(self, rhs: $narrow<C, D>) -> Self::Output {
+                // SAFETY: see the unsigned `Add` impl above -- the reasoning is unaffected by
+                // signedness.
+                unsafe {
+                    $wide::new_unchecked(self.inner() as $wide_ty + rhs.inner() as $wide_ty)
+                }
+            }
+        }
+
+        impl<const A: $narrow_ty, const B: $narrow_ty, const C: $narrow_ty, const D: $narrow_ty>
+            ::core::ops::Sub<$narrow<C, D>> for $narrow<A, B>
+        where
+            [(); { A as $wide_ty - D as $wide_ty } as usize]:,
+            [(); { B as $wide_ty - C as $wide_ty } as usize]:,
+        {
+            type Output =
+                $wide<{ A as $wide_ty - D as $wide_ty }, { B as $wide_ty - C as $wide_ty }>;
+
+            #[inline]
+            fn sub(self, rhs: $narrow<C, D>) -> Self::Output {
+                // SAFETY: see the unsigned `Sub` impl above.
+                unsafe {
+                    $wide::new_unchecked(self.inner() as $wide_ty - rhs.inner() as $wide_ty)
+                }
+            }
+        }
+    };
+}
+
+/// Implements widening `Mul` between two signed ranged types of the same width -- unlike the
+/// unsigned case, the product is not necessarily monotonic across a range that straddles (or is
+/// entirely below) zero, so the extrema are taken across all four corners of the bounding box.
+macro_rules! _widen_signed_mul {
+    ($narrow:tt($narrow_ty:ty), $wide:tt($wide_ty:ty)) => {
+        impl<const A: $narrow_ty, const B: $narrow_ty, const C: $narrow_ty, const D: $narrow_ty>
+            ::core::ops::Mul<$narrow<C, D>> for $narrow<A, B>
+        where
+            [(); {
+                _min4(
+                    A as i128 * C as i128,
+                    A as i128 * D as i128,
+                    B as i128 * C as i128,
+                    B as i128 * D as i128,
+                ) as $wide_ty as usize
+            }]:,
+            [(); {
+                _max4(
+                    A as i128 * C as i128,
+                    A as i128 * D as i128,
+                    B as i128 * C as i128,
+                    B as i128 * D as i128,
+                ) as $wide_ty as usize
+            }]:,
+        {
+            type Output = $wide<
+                {
+                    _min4(
+                        A as i128 * C as i128,
+                        A as i128 * D as i128,
+                        B as i128 * C as i128,
+                        B as i128 * D as i128,
+                    ) as $wide_ty
+                },
+                {
+                    _max4(
+                        A as i128 * C as i128,
+                        A as i128 * D as i128,
+                        B as i128 * C as i128,
+                        B as i128 * D as i128,
+                    ) as $wide_ty
+                },
+            >;
+
+            #[inline]
+            fn mul(self, rhs: $narrow<C, D>) -> Self::Output {
+                // SAFETY: the output bounds are the min/max of the four corner products, which
+                // bound every product of a value in `A..=B` and a value in `C..=D`.
+                unsafe {
+                    $wide::new_unchecked(self.inner() as $wide_ty * rhs.inner() as $wide_ty)
+                }
+            }
+        }
+    };
+}
+
+/// Implements an infallible widening `From` conversion from a ranged type to the ranged type of
+/// the next width up (or, from an unsigned type, to the signed type of the next width up), reusing
+/// the same bounds
+/// -- the value is unchanged, only cast to the wider base type, so it is trivially still within
+/// `A..=B`.
+macro_rules! _widen_from {
+    ($narrow:tt($narrow_ty:ty), $wide:tt($wide_ty:ty)) => {
+        impl<const A: $narrow_ty, const B: $narrow_ty> ::core::convert::From<$narrow<A, B>>
+            for $wide<{ A as $wide_ty }, { B as $wide_ty }>
+        where
+            [(); { A as $wide_ty } as usize]:,
+            [(); { B as $wide_ty } as usize]:,
+        {
+            #[inline]
+            fn from(value: $narrow<A, B>) -> Self {
+                // SAFETY: the output bounds are `A` and `B` cast to `$wide_ty`, and
+                // `value.inner()` is in `A..=B`, so the cast result is always in bounds.
+                unsafe { $wide::new_unchecked(value.inner() as $wide_ty) }
+            }
+        }
+    };
+}
+
+// Unsigned widening chain: u8 -> u16 -> u32 -> u64 -> u128 (Add/Mul), u8 -> i16, u16 -> i32,
+// u32 -> i64, u64 -> i128 (Sub).
+_widen_unsigned_add_mul!(RangedU8(u8), RangedU16(u16));
+_widen_unsigned_add_mul!(RangedU16(u16), RangedU32(u32));
+_widen_unsigned_add_mul!(RangedU32(u32), RangedU64(u64));
+_widen_unsigned_add_mul!(RangedU64(u64), RangedU128(u128));
+
+_widen_unsigned_sub!(RangedU8(u8), RangedI16(i16));
+_widen_unsigned_sub!(RangedU16(u16), RangedI32(i32));
+_widen_unsigned_sub!(RangedU32(u32), RangedI64(i64));
+_widen_unsigned_sub!(RangedU64(u64), RangedI128(i128));
+
+// Signed widening chain: i8 -> i16 -> i32 -> i64 -> i128.
+_widen_signed_add_sub!(RangedI8(i8), RangedI16(i16));
+_widen_signed_add_sub!(RangedI16(i16), RangedI32(i32));
+_widen_signed_add_sub!(RangedI32(i32), RangedI64(i64));
+_widen_signed_add_sub!(RangedI64(i64), RangedI128(i128));
+
+_widen_signed_mul!(RangedI8(i8), RangedI16(i16));
+_widen_signed_mul!(RangedI16(i16), RangedI32(i32));
+_widen_signed_mul!(RangedI32(i32), RangedI64(i64));
+_widen_signed_mul!(RangedI64(i64), RangedI128(i128));
+
+// Infallible cross-width `From` conversions, reusing the same bounds -- mirrors the chains above
+// rather than the `AssertWiden`-guarded same-width `expand` method on `Ranged*` itself (see
+// `numeric.rs`), which instead widens *within* a single base type.
+_widen_from!(RangedU8(u8), RangedU16(u16));
+_widen_from!(RangedU16(u16), RangedU32(u32));
+_widen_from!(RangedU32(u32), RangedU64(u64));
+_widen_from!(RangedU64(u64), RangedU128(u128));
+
+_widen_from!(RangedI8(i8), RangedI16(i16));
+_widen_from!(RangedI16(i16), RangedI32(i32));
+_widen_from!(RangedI32(i32), RangedI64(i64));
+_widen_from!(RangedI64(i64), RangedI128(i128));
+
+_widen_from!(RangedU8(u8), RangedI16(i16));
+_widen_from!(RangedU16(u16), RangedI32(i32));
+_widen_from!(RangedU32(u32), RangedI64(i64));
+_widen_from!(RangedU64(u64), RangedI128(i128));
@@ -0,0 +1,306 @@
+//! A storage-optimised ranged integer, gated behind the nightly-only `compact` feature.
+//!
+//! [`RangedCompact<MIN, MAX>`] mirrors the `ranged_integers` crate's "automatic data size
+//! selection" -- a `Ranged*` type declared via [`_int_define!`](super) always stores the full
+//! base integer (e.g. a `RangedU32<0, 200>` still occupies 4 bytes), whereas `RangedCompact`
+//! picks the smallest unsigned primitive that can hold `MAX - MIN` and stores the value as an
+//! offset from `MIN`, reconstructing the real value (as an `i128`) on [`inner`](RangedCompact::inner).
+//!
+//! # How the backing type is selected
+//!
+//! There is no way on stable Rust to make a struct field's type depend on the *value* of a const
+//! generic -- so, like [`arithmetic`](super::arithmetic), this module requires
+//! `generic_const_exprs` to compute, for each of `u8`/`u16`/`u32`/`u64`, whether the span fits (as
+//! a `bool` in const-generic position), and then dispatches on those bools through
+//! [`_SelectRepr`], a trait implemented only for the five valid combinations (each span fits
+//! a prefix of the list, since e.g. anything fitting in a `u8` also fits in a `u16`). Every public
+//! item in this module is bounded on [`_CompactRepr<MIN, MAX>`] rather than repeating the raw
+//! boolean expressions, since a `where` clause cannot be handed off to a macro in the way a type or
+//! expression can.
+
+use super::RangedError;
+
+/// The span (`MAX - MIN`) of a `MIN..=MAX` range, computed without overflowing `i128` even at the
+/// extremes (`i128::MIN..=i128::MAX`) -- see [`new_wrapping`](super::RangedI8::new_wrapping) for
+/// the same sign-bit-bias trick applied to modular arithmetic instead of a width computation.
+pub const fn _compact_span(min: i128, max: i128) -> u128 {
+    const SIGN_BIT: u128 = i128::MIN as u128;
+    (max as u128 ^ SIGN_BIT) - (min as u128 ^ SIGN_BIT)
+}
+
+/// The inverse of [`_compact_span`]: reconstructs `min + offset` without overflowing `i128`, even
+/// when `min` and `min + offset` sit at the extremes of the type's range.
+pub const fn _compact_unspan(min: i128, offset: u128) -> i128 {
+    const SIGN_BIT: u128 = i128::MIN as u128;
+    ((min as u128 ^ SIGN_BIT).wrapping_add(offset) ^ SIGN_BIT) as i128
+}
+
+/// Selects the smallest unsigned primitive that can hold a span, given whether that span fits in
+/// a `u8`/`u16`/`u32`/`u64` -- implemented only for the five combinations that can actually arise
+/// (each `FITS_*` implies every wider `FITS_*` after it), so naming any other combination is a
+/// compile error pointing back at the call site.
+pub trait _SelectRepr<const FITS_U8: bool, const FITS_U16: bool, const FITS_U32: bool, const FITS_U64: bool> {
+    /// The smallest primitive capable of holding the span.
+    type Repr: Copy;
+
+    /// Narrow a `MIN`-relative offset into [`Repr`](Self::Repr).
+    fn pack(offset: u128) -> Self::Repr;
+
+    /// Widen a stored [`Repr`](Self::Repr) back into a `MIN`-relative offset.
+    fn unpack(repr: Self::Repr) -> u128;
+}
+
+impl _SelectRepr<true, true, true, true> for () {
+    type Repr = u8;
+
+    #[inline]
+    fn pack(offset: u128) -> u8 {
+        offset as u8
+    }
+
+    #[inline]
+    fn unpack(repr: u8) -> u128 {
+        repr as u128
+    }
+}
+
+impl _SelectRepr<false, true, true, true> for () {
+    type Repr = u16;
+
+    #[inline]
+    fn pack(offset: u128) -> u16 {
+        offset as u16
+    }
+
+    #[inline]
+    fn unpack(repr: u16) -> u128 {
+        repr as u128
+    }
+}
+
+impl _SelectRepr<false, false, true, true> for () {
+    type Repr = u32;
+
+    #[inline]
+    fn pack(offset: u128) -> u32 {
+        offset as u32
+    }
+
+    #[inline]
+    fn unpack(repr: u32) -> u128 {
+        repr as u128
+    }
+}
+
+impl _SelectRepr<false, false, false, true> for () {
+    type Repr = u64;
+
+    #[inline]
+    fn pack(offset: u128) -> u64 {
+        offset as u64
+    }
+
+    #[inline]
+    fn unpack(repr: u64) -> u128 {
+        repr as u128
+    }
+}
+
+impl _SelectRepr<false, false, false, false> for () {
+    type Repr = u128;
+
+    #[inline]
+    fn pack(offset: u128) -> u128 {
+        offset
+    }
+
+    #[inline]
+    fn unpack(repr: u128) -> u128 {
+        repr
+    }
+}
+
+/// Resolves the backing [`Repr`](Self::Repr) for a given `MIN..=MAX`, hiding the raw boolean
+/// expressions required by [`_SelectRepr`] behind a plain `MIN`/`MAX`-parameterised bound so the
+/// rest of this module (and [`RangedCompact`] itself) can depend on it directly.
+pub trait _CompactRepr<const MIN: i128, const MAX: i128> {
+    /// The smallest primitive capable of holding `MAX - MIN`.
+    type Repr: Copy;
+
+    /// Narrow a `MIN`-relative offset into [`Repr`](Self::Repr).
+    fn pack(offset: u128) -> Self::Repr;
+
+    /// Widen a stored [`Repr`](Self::Repr) back into a `MIN`-relative offset.
+    fn unpack(repr: Self::Repr) -> u128;
+}
+
+impl<const MIN: i128, const MAX: i128> _CompactRepr<MIN, MAX> for ()
+where
+    (): _SelectRepr<
+        { _compact_span(MIN, MAX) <= u8::MAX as u128 },
+        { _compact_span(MIN, MAX) <= u16::MAX as u128 },
+        { _compact_span(MIN, MAX) <= u32::MAX as u128 },
+        { _compact_span(MIN, MAX) <= u64::MAX as u128 },
+    >,
+{
+    type Repr = <() as _SelectRepr<
+        { _compact_span(MIN, MAX) <= u8::MAX as u128 },
+        { _compact_span(MIN, MAX) <= u16::MAX as u128 },
+        { _compact_span(MIN, MAX) <= u32::MAX as u128 },
+        { _compact_span(MIN, MAX) <= u64::MAX as u128 },
+    >>::Repr;
+
+    #[inline]
+    fn pack(offset: u128) -> Self::Repr {
+        <() as _SelectRepr<
+            { _compact_span(MIN, MAX) <= u8::MAX as u128 },
+            { _compact_span(MIN, MAX) <= u16::MAX as u128 },
+            { _compact_span(MIN, MAX) <= u32::MAX as u128 },
+            { _compact_span(MIN, MAX) <= u64::MAX as u128 },
+        >>::pack(offset)
+    }
+
+    #[inline]
+    fn unpack(repr: Self::Repr) -> u128 {
+        <() as _SelectRepr<
+            { _compact_span(MIN, MAX) <= u8::MAX as u128 },
+            { _compact_span(MIN, MAX) <= u16::MAX as u128 },
+            { _compact_span(MIN, MAX) <= u32::MAX as u128 },
+            { _compact_span(MIN, MAX) <= u64::MAX as u128 },
+        >>::unpack(repr)
+    }
+}
+
+/// A ranged integer in `MIN..=MAX`, backed by the smallest unsigned primitive that can hold
+/// `MAX - MIN` rather than always occupying a full `i128` -- useful when a struct has many
+/// narrow-range fields and the 16-byte [`RangedI128`](super::RangedI128)/[`RangedU128`](super::RangedU128)
+/// storage would otherwise dominate its size.
+///
+/// Unlike the [`_int_define!`](super)-generated types, `RangedCompact` is not generic over a base
+/// integer type -- `MIN`/`MAX` are always `i128`, wide enough to express any range those types
+/// could, and [`inner`](Self::inner) always returns `i128`.
+pub struct RangedCompact<const MIN: i128, const MAX: i128>(<() as _CompactRepr<MIN, MAX>>::Repr)
+where
+    (): _CompactRepr<MIN, MAX>;
+
+impl<const MIN: i128, const MAX: i128> Clone for RangedCompact<MIN, MAX>
+where
+    (): _CompactRepr<MIN, MAX>,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<const MIN: i128, const MAX: i128> Copy for RangedCompact<MIN, MAX> where (): _CompactRepr<MIN, MAX> {}
+
+impl<const MIN: i128, const MAX: i128> ::core::fmt::Debug for RangedCompact<MIN, MAX>
+where
+    (): _CompactRepr<MIN, MAX>,
+{
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_tuple("RangedCompact").field(&self.inner()).finish()
+    }
+}
+
+impl<const MIN: i128, const MAX: i128> PartialEq for RangedCompact<MIN, MAX>
+where
+    (): _CompactRepr<MIN, MAX>,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.inner() == other.inner()
+    }
+}
+
+impl<const MIN: i128, const MAX: i128> Eq for RangedCompact<MIN, MAX> where (): _CompactRepr<MIN, MAX> {}
+
+impl<const MIN: i128, const MAX: i128> ::core::fmt::Display for RangedCompact<MIN, MAX>
+where
+    (): _CompactRepr<MIN, MAX>,
+{
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        ::core::fmt::Display::fmt(&self.inner(), f)
+    }
+}
+
+impl<const MIN: i128, const MAX: i128> RangedCompact<MIN, MAX>
+where
+    (): _CompactRepr<MIN, MAX>,
+{
+    /// Create a new instance of the type.
+    ///
+    /// # Parameters
+    ///
+    /// The value must be in the range `MIN..=MAX`.
+    pub fn new(value: i128) -> Result<Self, RangedError<i128>> {
+        if MIN > MAX {
+            return Err(RangedError::InvalidRange {
+                minimum: MIN,
+                maximum: MAX,
+            });
+        }
+
+        if value < MIN {
+            Err(RangedError::TooSmall {
+                value,
+                minimum: MIN,
+            })
+        } else if value > MAX {
+            Err(RangedError::TooLarge {
+                value,
+                maximum: MAX,
+            })
+        } else {
+            // SAFETY: just checked `MIN <= value <= MAX` above.
+            Ok(unsafe { Self::new_unchecked(value) })
+        }
+    }
+
+    /// Create a new instance of the type without a bounds check.
+    ///
+    /// # Safety
+    ///
+    /// The value **MUST** be within the range `MIN..=MAX` (if `MIN` is greater than `MAX`, this
+    /// function is **ALWAYS** unsafe to call).
+    #[inline]
+    pub unsafe fn new_unchecked(value: i128) -> Self {
+        // `_compact_span` computes `value - MIN` without overflowing `i128` even when both are
+        // at the extremes of the type's range (a plain `value - MIN` can overflow there).
+        let offset = _compact_span(MIN, value);
+        Self(<() as _CompactRepr<MIN, MAX>>::pack(offset))
+    }
+
+    /// Retrieve the inner value of the type.
+    ///
+    /// # Remarks
+    ///
+    /// This is guaranteed to return a value in the range `MIN..=MAX`.
+    #[inline]
+    pub fn inner(self) -> i128 {
+        _compact_unspan(MIN, <() as _CompactRepr<MIN, MAX>>::unpack(self.0))
+    }
+}
+
+impl<const MIN: i128, const MAX: i128> ::core::convert::TryFrom<i128> for RangedCompact<MIN, MAX>
+where
+    (): _CompactRepr<MIN, MAX>,
+{
+    type Error = RangedError<i128>;
+
+    #[inline]
+    fn try_from(value: i128) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl<const MIN: i128, const MAX: i128> From<RangedCompact<MIN, MAX>> for i128
+where
+    (): _CompactRepr<MIN, MAX>,
+{
+    #[inline]
+    fn from(value: RangedCompact<MIN, MAX>) -> Self {
+        value.inner()
+    }
+}
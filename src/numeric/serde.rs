@@ -0,0 +1,44 @@
+//! `Serialize`/`Deserialize` implementations for ranged integers, gated behind the `serde`
+//! feature.
+//!
+//! Serialisation writes the base integer directly; deserialisation reads the base integer and
+//! routes it through [`new`](super::RangedU8::new) (using [`RangedU8`](super::RangedU8) here only
+//! as a stand-in -- every `Ranged*` type follows the same pattern), surfacing an out-of-range
+//! value as a `serde` error via [`RangedError`](super::RangedError)'s `Display` text.
+
+use super::*;
+
+macro_rules! _int_serde {
+    ($name:tt($int_ty:ty)) => {
+        impl<const MIN: $int_ty, const MAX: $int_ty> ::serde::Serialize for $name<MIN, MAX> {
+            #[inline]
+            fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                ::serde::Serialize::serialize(&self.inner(), serializer)
+            }
+        }
+
+        impl<'de, const MIN: $int_ty, const MAX: $int_ty> ::serde::Deserialize<'de>
+            for $name<MIN, MAX>
+        {
+            #[inline]
+            fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let value = <$int_ty as ::serde::Deserialize<'de>>::deserialize(deserializer)?;
+                Self::new(value).map_err(::serde::de::Error::custom)
+            }
+        }
+    };
+}
+
+_int_serde!(RangedU8(u8));
+_int_serde!(RangedU16(u16));
+_int_serde!(RangedU32(u32));
+_int_serde!(RangedU64(u64));
+_int_serde!(RangedU128(u128));
+_int_serde!(RangedUsize(usize));
+
+_int_serde!(RangedI8(i8));
+_int_serde!(RangedI16(i16));
+_int_serde!(RangedI32(i32));
+_int_serde!(RangedI64(i64));
+_int_serde!(RangedI128(i128));
+_int_serde!(RangedIsize(isize));